@@ -25,6 +25,28 @@ impl<M> MapType<M> for M {
     }
 }
 
+/// Analogous to `map_type` but for conversions that can fail.
+/// Transforms one type into another, threading the `Result` through.
+///
+/// # Examples
+///
+/// ```
+/// use maptypings::TryMapType;
+///
+/// let raw: &str = "42";
+/// let num: Result<u32, std::num::ParseIntError> = raw.try_map_type(|s| s.parse());
+/// ```
+pub trait TryMapType<M> {
+    /// Converts one type into another, or propagates the conversion's error.
+    fn try_map_type<N, E>(self, f: impl FnOnce(M) -> Result<N, E>) -> Result<N, E>;
+}
+
+impl<M> TryMapType<M> for M {
+    fn try_map_type<N, E>(self, f: impl FnOnce(Self) -> Result<N, E>) -> Result<N, E> {
+        f(self)
+    }
+}
+
 /// Wraps type in `Option` and returns `None` if condition is true.
 ///
 /// # Examples
@@ -49,6 +71,30 @@ impl<T> NoneIf<T> for T {
     }
 }
 
+/// Wraps type in `Option` and returns `Some` only if condition is true.
+///
+/// # Examples
+///
+/// ```
+/// use maptypings::SomeIf;
+///
+/// let s: String = "hello".to_owned();
+/// let optional: Option<String> = s.some_if(|s| !s.is_empty());
+/// ```
+pub trait SomeIf<T> {
+    /// Returns `Some(self)` on `cond == true`, else `None`.
+    fn some_if(self, cond: impl Fn(&T) -> bool) -> Option<T>;
+}
+
+impl<T> SomeIf<T> for T {
+    fn some_if(self, cond: impl Fn(&Self) -> bool) -> Option<Self> {
+        match cond(&self) {
+            true => Some(self),
+            _ => None,
+        }
+    }
+}
+
 /// Wraps type in `Result` and returns `Err` if condition is true.
 ///
 /// # Examples
@@ -64,6 +110,15 @@ impl<T> NoneIf<T> for T {
 pub trait ErrIf<T> {
     /// Returns a given error on `cond == true`.
     fn err_if<E>(self, cond: impl Fn(&Self) -> bool, err: E) -> Result<T, E>;
+    /// Returns an error built by `make_err` on `cond == true`.
+    ///
+    /// Unlike `err_if`, the error is only constructed when it's actually
+    /// needed, same as `Option::ok_or_else` vs `Option::ok_or`.
+    fn err_if_else<E>(
+        self,
+        cond: impl Fn(&Self) -> bool,
+        make_err: impl FnOnce() -> E,
+    ) -> Result<T, E>;
 }
 
 impl<T> ErrIf<T> for T {
@@ -73,6 +128,42 @@ impl<T> ErrIf<T> for T {
             _ => Ok(self),
         }
     }
+    fn err_if_else<E>(
+        self,
+        cond: impl Fn(&Self) -> bool,
+        make_err: impl FnOnce() -> E,
+    ) -> Result<Self, E> {
+        match cond(&self) {
+            true => Err(make_err()),
+            _ => Ok(self),
+        }
+    }
+}
+
+/// Wraps type in `Result` and returns `Ok` only if condition is true.
+///
+/// # Examples
+///
+/// ```
+/// use maptypings::OkIf;
+///
+/// let name: &str = "John";
+/// let err: String = "Error: something went wrong".to_owned();
+///
+/// let name: Result<&str, String> = name.ok_if(|s| !s.is_empty(), err);
+/// ```
+pub trait OkIf<T> {
+    /// Returns `Ok(self)` on `cond == true`, else the given error.
+    fn ok_if<E>(self, cond: impl Fn(&T) -> bool, err: E) -> Result<T, E>;
+}
+
+impl<T> OkIf<T> for T {
+    fn ok_if<E>(self, cond: impl Fn(&Self) -> bool, err: E) -> Result<Self, E> {
+        match cond(&self) {
+            true => Ok(self),
+            _ => Err(err),
+        }
+    }
 }
 
 /// Maps any value to `()`.
@@ -162,6 +253,10 @@ pub trait AddToRes<T> {
     fn add_ok<O>(self, ok: O) -> Result<O, T>;
     /// Turns `Option<T>` into `Result<T, E>`.
     fn add_err<E>(self, err: E) -> Result<T, E>;
+    /// Turns `Option<T>` into `Result<O, T>`, building the `Ok` value lazily.
+    fn add_ok_else<O>(self, make_ok: impl FnOnce() -> O) -> Result<O, T>;
+    /// Turns `Option<T>` into `Result<T, E>`, building the `Err` value lazily.
+    fn add_err_else<E>(self, make_err: impl FnOnce() -> E) -> Result<T, E>;
 }
 
 impl<T> AddToRes<T> for Option<T> {
@@ -174,6 +269,15 @@ impl<T> AddToRes<T> for Option<T> {
     fn add_err<E>(self, err: E) -> Result<T, E> {
         self.ok_or(err)
     }
+    fn add_ok_else<O>(self, make_ok: impl FnOnce() -> O) -> Result<O, T> {
+        match self {
+            None => Ok(make_ok()),
+            Some(e) => Err(e),
+        }
+    }
+    fn add_err_else<E>(self, make_err: impl FnOnce() -> E) -> Result<T, E> {
+        self.ok_or_else(make_err)
+    }
 }
 
 /// Mutates value and returns it back.
@@ -197,3 +301,80 @@ impl<T> Mutate<T> for T {
         val
     }
 }
+
+/// Peeks at value with a `&`-borrowing closure and returns it unchanged.
+///
+/// Note: on `Option<T>` and `Result<T, E>` this trait method is shadowed by
+/// the inherent `Option::inspect`/`Result::inspect` from std (stable since
+/// 1.76), so `.inspect(...)` there only sees the `Some`/`Ok` payload, not
+/// the whole value. On types that implement `Iterator`, `.inspect(...)` is
+/// ambiguous with `Iterator::inspect` while both traits are in scope and does
+/// not compile; `Inspect::inspect(value, f)` selects this trait there.
+///
+/// # Examples
+///
+/// ```
+/// use maptypings::Inspect;
+///
+/// let sum = vec![1, 2, 3].inspect(|v| println!("before sum: {v:?}")).iter().sum::<i32>();
+/// ```
+pub trait Inspect<T> {
+    /// Passes `&T` to `f` and returns the original value untouched.
+    fn inspect(self, f: impl FnOnce(&T)) -> T;
+}
+
+impl<T> Inspect<T> for T {
+    fn inspect(self, f: impl FnOnce(&Self)) -> Self {
+        f(&self);
+        self
+    }
+}
+
+/// Collapses `Option<T>` into a plain value, same as `Option::map_or`.
+///
+/// # Examples
+///
+/// ```
+/// use maptypings::FoldOpt;
+///
+/// let opt: Option<u32> = Some(16);
+/// let len: usize = opt.fold_opt(0, |n| n.to_string().len());
+/// ```
+pub trait FoldOpt<T> {
+    /// Applies `some` to the wrapped value, or returns `none` if there isn't one.
+    fn fold_opt<N>(self, none: N, some: impl FnOnce(T) -> N) -> N;
+}
+
+impl<T> FoldOpt<T> for Option<T> {
+    fn fold_opt<N>(self, none: N, some: impl FnOnce(T) -> N) -> N {
+        match self {
+            Some(t) => some(t),
+            None => none,
+        }
+    }
+}
+
+/// Collapses `Result<T, E>` into a plain value, same as `Result::map_or_else`
+/// but with the `Ok` closure first and the `Err` closure second.
+///
+/// # Examples
+///
+/// ```
+/// use maptypings::FoldRes;
+///
+/// let res: Result<u32, String> = Ok(16);
+/// let len: usize = res.fold_res(|n| n.to_string().len(), |e| e.len());
+/// ```
+pub trait FoldRes<T, E> {
+    /// Applies `ok` to the success value or `err` to the error value.
+    fn fold_res<N>(self, ok: impl FnOnce(T) -> N, err: impl FnOnce(E) -> N) -> N;
+}
+
+impl<T, E> FoldRes<T, E> for Result<T, E> {
+    fn fold_res<N>(self, ok: impl FnOnce(T) -> N, err: impl FnOnce(E) -> N) -> N {
+        match self {
+            Ok(t) => ok(t),
+            Err(e) => err(e),
+        }
+    }
+}